@@ -9,6 +9,7 @@ use std::env;
 use std::fs::File;
 use std::io::Write;
 use std::path::Path;
+use std::process::Command;
 
 pub fn main() {
     const ENV_FEATURE_PREFIX: &str = "CARGO_FEATURE_";
@@ -19,6 +20,8 @@ pub fn main() {
         println!("cargo:rustc-cfg=build={profile:?}");
     }
 
+    emit_version_metadata();
+
     let out_dir = env::var("OUT_DIR").unwrap();
 
     let mut crates = Vec::new();
@@ -100,3 +103,38 @@ pub fn main() {
 
     mf.flush().unwrap();
 }
+
+/// Exposes `git describe`, the `rustc` version, and the set of enabled Cargo
+/// features as compile-time environment variables, so binaries like `gnostr`
+/// can report real build metadata from `--version`.
+fn emit_version_metadata() {
+    let git_describe = Command::new("git")
+        .args(["describe", "--always", "--dirty", "--tags"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map_or_else(|| "unknown".to_owned(), |s| s.trim().to_owned());
+    println!("cargo:rustc-env=GNOSTR_GIT_DESCRIBE={git_describe}");
+    println!("cargo:rerun-if-changed=.git/HEAD");
+
+    let rustc = env::var_os("RUSTC").unwrap_or_else(|| "rustc".into());
+    let rustc_version = Command::new(rustc)
+        .arg("--version")
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map_or_else(|| "unknown".to_owned(), |s| s.trim().to_owned());
+    println!("cargo:rustc-env=GNOSTR_RUSTC_VERSION={rustc_version}");
+
+    let mut enabled_features: Vec<String> = env::vars()
+        .filter(|(key, val)| val == "1" && key.starts_with("CARGO_FEATURE_"))
+        .map(|(key, _)| key["CARGO_FEATURE_".len()..].to_lowercase())
+        .collect();
+    enabled_features.sort();
+    println!(
+        "cargo:rustc-env=GNOSTR_ENABLED_FEATURES={}",
+        enabled_features.join(",")
+    );
+}
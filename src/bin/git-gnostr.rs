@@ -16,6 +16,9 @@ use std::process;
 use uucore::display::Quotable;
 
 const VERSION: &str = env!("CARGO_PKG_VERSION");
+const GIT_DESCRIBE: &str = env!("GNOSTR_GIT_DESCRIBE");
+const RUSTC_VERSION: &str = env!("GNOSTR_RUSTC_VERSION");
+const ENABLED_FEATURES: &str = env!("GNOSTR_ENABLED_FEATURES");
 
 include!(concat!(env!("OUT_DIR"), "/uutils_map.rs"));
 
@@ -93,7 +96,9 @@ fn main() {
 
         match util {
             "completion" => gen_completions(args, &utils),
+            "completions" => gen_self_completions(args, &utils, binary_as_util),
             "manpage" => gen_manpage(args, &utils),
+            "--version" | "-V" => print_version(args, binary_as_util),
             "--list" => {
                 let mut utils: Vec<_> = utils.keys().collect();
                 utils.sort();
@@ -144,6 +149,67 @@ fn main() {
     }
 }
 
+/// Prints the version of the invoked binary (`name`), sourced from Cargo
+/// metadata, `git describe` and the `rustc` version.
+///
+/// With a trailing `--json` argument, prints the same information (plus the
+/// set of enabled Cargo features) as a JSON object instead of the
+/// human-readable line.
+fn print_version(mut args: impl Iterator<Item = OsString>, name: &str) -> ! {
+    let want_json = matches!(args.next(), Some(arg) if arg == "--json");
+    if want_json {
+        let features = ENABLED_FEATURES
+            .split(',')
+            .filter(|f| !f.is_empty())
+            .map(|f| format!("\"{f}\""))
+            .collect::<Vec<_>>()
+            .join(",");
+        println!(
+            "{{\"name\":\"{name}\",\"version\":\"{VERSION}\",\"git_describe\":\"{GIT_DESCRIBE}\",\"rustc_version\":\"{RUSTC_VERSION}\",\"features\":[{features}]}}"
+        );
+    } else {
+        println!("{name} {VERSION} ({GIT_DESCRIBE}, {RUSTC_VERSION})");
+    }
+    process::exit(0);
+}
+
+/// Prints completions for the whole multicall binary (all subcommands) for
+/// the shell in the first parameter to stdout.
+/// # Panics
+/// Panics if an applet has no `about` text, or if writing to stdout fails
+/// with an error other than a broken pipe
+fn gen_self_completions<T: uucore::Args>(
+    args: impl Iterator<Item = OsString>,
+    util_map: &UtilityMap<T>,
+    bin_name: &str,
+) -> ! {
+    let matches = Command::new("completions")
+        .about("Prints completions for this multicall binary to stdout")
+        .arg(
+            Arg::new("shell")
+                .value_parser(clap::builder::EnumValueParser::<Shell>::new())
+                .required(true),
+        )
+        .get_matches_from(std::iter::once(OsString::from("completions")).chain(args));
+
+    let shell = *matches.get_one::<Shell>("shell").unwrap();
+
+    // Generate into an in-memory buffer rather than stdout directly: the
+    // whole-multicall script is large enough (unlike the single-applet
+    // `completion` output) that piping it into `head` or a pager reliably
+    // closes the read end while clap_complete is still writing, and
+    // clap_complete panics on a write error instead of exiting quietly.
+    let mut command = gen_coreutils_app(util_map);
+    let mut buf = Vec::new();
+    clap_complete::generate(shell, &mut command, bin_name, &mut buf);
+
+    match io::stdout().write_all(&buf).and_then(|()| io::stdout().flush()) {
+        Ok(()) => process::exit(0),
+        Err(e) if e.kind() == io::ErrorKind::BrokenPipe => process::exit(0),
+        Err(e) => panic!("failed to write completions: {e}"),
+    }
+}
+
 /// Prints completions for the utility in the first parameter for the shell in the second parameter to stdout
 /// # Panics
 /// Panics if the utility map is empty